@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::hyperloglog::HyperLogLog;
+
+/// A single named conformance case: replay `seed` through the target and expect `expected_hash`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Vector {
+    pub name: String,
+    pub seed: u64,
+    pub expected_hash: u64,
+}
+
+pub fn load(path: &str) -> anyhow::Result<Vec<Vector>> {
+    let file = std::fs::File::open(path)?;
+    Ok(serde_json::from_reader(file)?)
+}
+
+pub fn save(path: &str, vectors: &[Vector]) -> anyhow::Result<()> {
+    let file = std::fs::File::create(path)?;
+    serde_json::to_writer_pretty(file, vectors)?;
+    Ok(())
+}
+
+/// Snapshots an HLL's exemplar seeds/hashes into conformance vectors, so a passing fuzzing
+/// run can be committed as golden test cases independent of the sketch that grew them.
+pub fn from_hll(hll: &HyperLogLog) -> Vec<Vector> {
+    hll.seeds
+        .iter()
+        .zip(hll.hashes.iter())
+        .map(|(&seed, &hash)| Vector {
+            name: format!("seed-{}", seed),
+            seed,
+            expected_hash: hash,
+        })
+        .collect()
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// A single recorded failing seed, alongside the stdout/stderr captured when it failed.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Failure {
+    pub seed: u64,
+    pub message: String,
+}
+
+fn failures_path(target: &str) -> String {
+    format!("{}.failures.json", target)
+}
+
+/// Loads the failures recorded for `target`, or an empty corpus if the file doesn't exist
+/// yet. A file that exists but fails to parse is a corrupt corpus, not an empty one — that
+/// error is propagated so callers don't silently `save()` over (and lose) the seeds in it.
+pub fn load(target: &str) -> anyhow::Result<Vec<Failure>> {
+    let file = match std::fs::File::open(failures_path(target)) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    serde_json::from_reader(file)
+        .map_err(|err| anyhow::anyhow!("corrupt corpus file {}: {}", failures_path(target), err))
+}
+
+fn save(target: &str, failures: &[Failure]) -> anyhow::Result<()> {
+    let file = std::fs::File::create(failures_path(target))?;
+    serde_json::to_writer_pretty(file, failures)?;
+    Ok(())
+}
+
+/// Appends a single failing seed to `target`'s corpus file.
+pub fn append(target: &str, failure: Failure) -> anyhow::Result<()> {
+    let mut failures = load(target)?;
+    failures.push(failure);
+    save(target, &failures)
+}
+
+/// Appends a batch of failing seeds in one read-modify-write, so parallel workers can hand
+/// their failures to a single writer instead of racing on the corpus file.
+pub fn append_all(target: &str, new_failures: impl IntoIterator<Item = Failure>) -> anyhow::Result<()> {
+    let mut failures = load(target)?;
+    failures.extend(new_failures);
+    save(target, &failures)
+}
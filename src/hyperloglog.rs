@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+/// A HyperLogLog sketch over the space of `u64` hash outputs produced by a wasm target.
+///
+/// Alongside the usual dense register array, this sketch keeps the raw `seeds`/`hashes`
+/// pairs that advanced each register, so a `Verify` run can replay the exact inputs that
+/// grew the estimate instead of just trusting the count.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HyperLogLog {
+    pub p: u8,
+    pub registers: Vec<u8>,
+    pub seeds: Vec<u64>,
+    pub hashes: Vec<u64>,
+}
+
+impl HyperLogLog {
+    pub fn new(p: u8) -> Self {
+        let m = 1usize << p;
+        Self {
+            p,
+            registers: vec![0; m],
+            seeds: Vec::new(),
+            hashes: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, seed: u64, hash: u64) {
+        let m = self.registers.len();
+        let index = (hash as usize) & (m - 1);
+        let w = hash >> self.p;
+        let rank = (w.trailing_zeros() as u8) + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+            self.seeds.push(seed);
+            self.hashes.push(hash);
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let alpha_m = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            m => 0.7213 / (1.0 + 1.079 / m as f64),
+        };
+        let raw_estimate = alpha_m * m * m / sum;
+
+        if raw_estimate <= 2.5 * m {
+            let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+            if zeros > 0 {
+                return (m * (m / zeros as f64).ln()).round() as u64;
+            }
+        }
+        raw_estimate.round() as u64
+    }
+
+    /// Merges `other`'s registers and exemplars into `self`.
+    ///
+    /// Both sketches must share the same precision `p` (and therefore register count);
+    /// merging sketches built at different precisions would silently misinterpret bits
+    /// of the hash, so this returns an error instead of producing a nonsense estimate.
+    pub fn merge(&mut self, other: &HyperLogLog) -> anyhow::Result<()> {
+        if self.p != other.p {
+            return Err(anyhow::anyhow!(
+                "cannot merge HyperLogLog sketches with different precision ({} vs {})",
+                self.p,
+                other.p
+            ));
+        }
+        for (r, &o) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if o > *r {
+                *r = o;
+            }
+        }
+
+        let mut seen: std::collections::HashSet<(u64, u64)> = self
+            .seeds
+            .iter()
+            .copied()
+            .zip(self.hashes.iter().copied())
+            .collect();
+        for (&seed, &hash) in other.seeds.iter().zip(other.hashes.iter()) {
+            if seen.insert((seed, hash)) {
+                self.seeds.push(seed);
+                self.hashes.push(hash);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_takes_elementwise_max_of_registers() {
+        let mut a = HyperLogLog::new(2);
+        a.registers = vec![1, 5, 0, 3];
+        let mut b = HyperLogLog::new(2);
+        b.registers = vec![4, 2, 0, 3];
+
+        a.merge(&b).unwrap();
+
+        assert_eq!(a.registers, vec![4, 5, 0, 3]);
+    }
+
+    #[test]
+    fn merge_rejects_mismatched_precision() {
+        let mut a = HyperLogLog::new(2);
+        let b = HyperLogLog::new(3);
+
+        assert!(a.merge(&b).is_err());
+    }
+
+    #[test]
+    fn merge_deduplicates_exemplars() {
+        let mut a = HyperLogLog::new(2);
+        a.seeds = vec![1, 2];
+        a.hashes = vec![10, 20];
+        let mut b = HyperLogLog::new(2);
+        b.seeds = vec![2, 3];
+        b.hashes = vec![20, 30];
+
+        a.merge(&b).unwrap();
+
+        assert_eq!(a.seeds, vec![1, 2, 3]);
+        assert_eq!(a.hashes, vec![10, 20, 30]);
+    }
+}
@@ -1,5 +1,8 @@
 use std::io::Cursor;
-use std::sync::Arc;
+use std::sync::mpsc;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use clap::{Parser, Subcommand};
 use hyperloglog::HyperLogLog;
@@ -7,9 +10,17 @@ use rand::rngs::StdRng;
 use rand::Rng;
 use rand::SeedableRng;
 use wasi_common::{pipe::WritePipe, sync::WasiCtxBuilder};
-use wasmtime::{Engine, Linker, Module, Store};
+use wasmtime::{Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder, TypedFunc};
 
+/// Memory ceiling applied to every fuzzed `Store`, so a target that allocates without
+/// bound is reported as a failure rather than taking down the runner with an OOM.
+const MEMORY_LIMIT_BYTES: usize = 256 * 1024 * 1024;
+
+mod conformance;
+mod corpus;
 mod hyperloglog;
+mod report;
+mod trace;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -27,10 +38,59 @@ enum Commands {
         iterations: u64,
         #[arg(long, help = "Optional seed for the test")]
         initial_seed: Option<u64>,
+        #[arg(long, default_value_t = 1, help = "Number of worker threads to fuzz with")]
+        jobs: usize,
+        #[arg(long, help = "Fuel budget per call; traps the target instead of hanging forever")]
+        max_fuel: Option<u64>,
+        #[arg(long, help = "Wall-clock deadline per call in milliseconds")]
+        timeout_ms: Option<u64>,
+        #[arg(long, help = "Write a JUnit XML report to this path")]
+        report: Option<String>,
+        #[arg(long, help = "Stream NDJSON iteration events to this path")]
+        events: Option<String>,
     },
     Verify {
         #[arg(help = "The target to verify")]
         target: String,
+        #[arg(long, help = "Fuel budget per call; traps the target instead of hanging forever")]
+        max_fuel: Option<u64>,
+        #[arg(long, help = "Wall-clock deadline per call in milliseconds")]
+        timeout_ms: Option<u64>,
+        #[arg(long, help = "Write a JUnit XML report to this path")]
+        report: Option<String>,
+        #[arg(long, help = "Stream NDJSON iteration events to this path")]
+        events: Option<String>,
+    },
+    Merge {
+        #[arg(required = true, help = "HyperLogLog sketch files (e.g. target.json) to merge")]
+        inputs: Vec<String>,
+        #[arg(long, help = "Path to write the merged sketch to")]
+        output: String,
+    },
+    Minimize {
+        #[arg(help = "The target the failing seed was recorded against")]
+        target: String,
+        #[arg(long, help = "Which recorded failing seed to shrink (defaults to the latest)")]
+        seed: Option<u64>,
+        #[arg(long, help = "Fuel budget per call; traps the target instead of hanging forever")]
+        max_fuel: Option<u64>,
+        #[arg(long, help = "Wall-clock deadline per call in milliseconds")]
+        timeout_ms: Option<u64>,
+    },
+    Check {
+        #[arg(help = "The target to check")]
+        target: String,
+        #[arg(long, help = "JSON file of named { name, seed, expected_hash } vectors to replay")]
+        vectors: Option<String>,
+        #[arg(
+            long,
+            help = "Instead of checking, export the target's current HLL exemplars as a vectors file"
+        )]
+        export: Option<String>,
+        #[arg(long, help = "Fuel budget per call; traps the target instead of hanging forever")]
+        max_fuel: Option<u64>,
+        #[arg(long, help = "Wall-clock deadline per call in milliseconds")]
+        timeout_ms: Option<u64>,
     },
 }
 
@@ -46,33 +106,96 @@ fn save_hll(path: &str, hll: &HyperLogLog) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Store data for a fuzzed instance: the WASI context backing its stdio, plus the
+/// resource limiter that caps its memory growth.
+struct StoreState {
+    wasi: wasi_common::WasiCtx,
+    limits: StoreLimits,
+}
+
+type Stdio = Arc<RwLock<Cursor<Vec<u8>>>>;
+
+/// Builds an `Engine` configured for the resource limits the caller asked for: fuel
+/// accounting when `max_fuel` is set, epoch-based interruption when `timeout_ms` is set.
+/// Both are opt-in since they add per-call overhead that untrusted-input fuzzing doesn't
+/// always need.
+fn build_engine(max_fuel: Option<u64>, timeout_ms: Option<u64>) -> anyhow::Result<Engine> {
+    let mut config = Config::new();
+    config.consume_fuel(max_fuel.is_some());
+    config.epoch_interruption(timeout_ms.is_some());
+    config.wasm_backtrace(true);
+    Ok(Engine::new(&config)?)
+}
+
+/// Spawns a background thread that ticks `engine`'s epoch every `timeout_ms`, arming the
+/// epoch-deadline traps set up by callers via `Store::set_epoch_deadline`. The thread is
+/// intentionally detached: it lives for the process, same as the engine it drives.
+fn spawn_epoch_ticker(engine: &Engine, timeout_ms: Option<u64>) {
+    if let Some(timeout_ms) = timeout_ms {
+        let engine = engine.clone();
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_millis(timeout_ms));
+            engine.increment_epoch();
+        });
+    }
+}
+
+/// Instantiates `module` into a fresh `Store`, wiring up stdout/stderr capture and a
+/// memory limiter. Returns the store alongside the pipes and the exported `test` function,
+/// so both the serial and parallel fuzzing paths can share this setup.
+fn instantiate(
+    engine: &Engine,
+    module: &Module,
+) -> anyhow::Result<(Store<StoreState>, Stdio, Stdio, TypedFunc<u64, u64>)> {
+    let mut linker = Linker::new(engine);
+    wasi_common::sync::add_to_linker(&mut linker, |s: &mut StoreState| &mut s.wasi)?;
+    let stdout: Stdio = Arc::new(RwLock::new(Cursor::new(Vec::new())));
+    let stderr: Stdio = Arc::new(RwLock::new(Cursor::new(Vec::new())));
+    let wasi = WasiCtxBuilder::new()
+        .stdout(Box::new(WritePipe::from_shared(stdout.clone())))
+        .stderr(Box::new(WritePipe::from_shared(stderr.clone())))
+        .build();
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(MEMORY_LIMIT_BYTES)
+        .build();
+    let mut store = Store::new(engine, StoreState { wasi, limits });
+    store.limiter(|state| &mut state.limits);
+    let instance = linker.instantiate(&mut store, module)?;
+    let test = instance.get_typed_func::<u64, u64>(&mut store, "test")?;
+    Ok((store, stdout, stderr, test))
+}
+
+fn read_pipe(pipe: &Stdio) -> anyhow::Result<String> {
+    Ok(String::from_utf8(pipe.read().unwrap().get_ref().clone())?)
+}
+
+fn reset_pipe(pipe: &Stdio) {
+    *pipe.write().unwrap() = Cursor::new(Vec::new());
+}
+
 struct WasmTest {
     target: String,
     hll: HyperLogLog,
-    store: wasmtime::Store<wasi_common::WasiCtx>,
-    stdout: Arc<std::sync::RwLock<Cursor<Vec<u8>>>>,
-    stderr: Arc<std::sync::RwLock<Cursor<Vec<u8>>>>,
-    test: wasmtime::TypedFunc<u64, u64>,
+    store: Store<StoreState>,
+    stdout: Stdio,
+    stderr: Stdio,
+    test: TypedFunc<u64, u64>,
+    max_fuel: Option<u64>,
+    timeout_ms: Option<u64>,
+    debug_info: Option<trace::DebugInfo>,
 }
 
 impl WasmTest {
-    fn new(target: &str) -> anyhow::Result<Self> {
+    fn new(target: &str, max_fuel: Option<u64>, timeout_ms: Option<u64>) -> anyhow::Result<Self> {
         let hll = load_hll(&format!("{}.json", target))
             .ok()
             .unwrap_or(HyperLogLog::new(6));
-        let engine = Engine::default();
-        let module = Module::from_file(&engine, target)?;
-        let mut linker = Linker::new(&engine);
-        wasi_common::sync::add_to_linker(&mut linker, |s| s)?;
-        let stdout = Arc::new(std::sync::RwLock::new(Cursor::new(Vec::new())));
-        let stderr = Arc::new(std::sync::RwLock::new(Cursor::new(Vec::new())));
-        let wasi = WasiCtxBuilder::new()
-            .stdout(Box::new(WritePipe::from_shared(stdout.clone())))
-            .stderr(Box::new(WritePipe::from_shared(stderr.clone())))
-            .build();
-        let mut store = Store::new(&engine, wasi);
-        let instance = linker.instantiate(&mut store, &module)?;
-        let test = instance.get_typed_func::<u64, u64>(&mut store, "test")?;
+        let engine = build_engine(max_fuel, timeout_ms)?;
+        spawn_epoch_ticker(&engine, timeout_ms);
+        let wasm_bytes = std::fs::read(target)?;
+        let module = Module::from_binary(&engine, &wasm_bytes)?;
+        let debug_info = trace::DebugInfo::load(&wasm_bytes)?;
+        let (store, stdout, stderr, test) = instantiate(&engine, &module)?;
         Ok(Self {
             target: target.to_string(),
             hll,
@@ -80,16 +203,37 @@ impl WasmTest {
             stdout,
             stderr,
             test,
+            max_fuel,
+            timeout_ms,
+            debug_info,
         })
     }
 
     fn run(&mut self, seed: u64) -> anyhow::Result<Result<u64, String>> {
-        *self.stdout.write().unwrap() = Cursor::new(Vec::new());
-        *self.stderr.write().unwrap() = Cursor::new(Vec::new());
-        let result = self.test.call(&mut self.store, seed)?;
+        reset_pipe(&self.stdout);
+        reset_pipe(&self.stderr);
+        if let Some(fuel) = self.max_fuel {
+            self.store.set_fuel(fuel)?;
+        }
+        if self.timeout_ms.is_some() {
+            // Two ticks, so a call is only interrupted once it has spanned a full tick period.
+            self.store.set_epoch_deadline(2);
+        }
+        let result = match self.test.call(&mut self.store, seed) {
+            Ok(result) => result,
+            Err(trap) => {
+                let stdout = read_pipe(&self.stdout)?;
+                let stderr = read_pipe(&self.stderr)?;
+                let backtrace = trace::render_trap_backtrace(self.debug_info.as_ref(), &trap);
+                return Ok(Err(format!(
+                    "trap: {}\n{}stdout:\n{}\nstderr:\n{}",
+                    trap, backtrace, stdout, stderr
+                )));
+            }
+        };
         if result == u64::MAX {
-            let stdout = String::from_utf8(self.stdout.read().unwrap().get_ref().clone())?;
-            let stderr = String::from_utf8(self.stderr.read().unwrap().get_ref().clone())?;
+            let stdout = read_pipe(&self.stdout)?;
+            let stderr = read_pipe(&self.stderr)?;
             return Ok(Err(format!("stdout:\n{}\nstderr:\n{}", stdout, stderr)));
         }
         self.hll.add(seed, result);
@@ -102,6 +246,210 @@ impl WasmTest {
     }
 }
 
+/// Fuzzes `target` across `jobs` worker threads, each with its own `Store` instantiated
+/// from a shared compiled `Module` (`wasmtime::Store` is not `Sync`, so sharing one across
+/// threads isn't an option). Each worker accumulates into its own `HyperLogLog`, seeded
+/// deterministically from `initial_seed.wrapping_add(worker_idx)` so a run stays
+/// reproducible for a fixed `--jobs` and `--initial-seed` (changing `--jobs` changes both
+/// the per-worker seeds and how the iterations are split, so it changes coverage too), and
+/// the sketches are merged into the on-disk one once every worker finishes.
+fn run_parallel(
+    target: &str,
+    iterations: u64,
+    initial_seed: Option<u64>,
+    jobs: usize,
+    max_fuel: Option<u64>,
+    timeout_ms: Option<u64>,
+    report_path: Option<String>,
+    events: Option<report::EventLog>,
+) -> anyhow::Result<()> {
+    let start = Instant::now();
+    let engine = Arc::new(build_engine(max_fuel, timeout_ms)?);
+    spawn_epoch_ticker(&engine, timeout_ms);
+    let wasm_bytes = std::fs::read(target)?;
+    let module = Arc::new(Module::from_binary(engine.as_ref(), &wasm_bytes)?);
+    let debug_info = Arc::new(trace::DebugInfo::load(&wasm_bytes)?);
+    let base_hll = load_hll(&format!("{}.json", target))
+        .ok()
+        .unwrap_or(HyperLogLog::new(6));
+    let p = base_hll.p;
+    let base_seed = initial_seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+
+    let per_worker = iterations / jobs as u64;
+    let remainder = iterations % jobs as u64;
+
+    let (failure_tx, failure_rx) = mpsc::channel::<(u64, String)>();
+    let mut handles = Vec::with_capacity(jobs);
+    for worker_idx in 0..jobs {
+        let engine = engine.clone();
+        let module = module.clone();
+        let tx = failure_tx.clone();
+        let worker_seed = base_seed.wrapping_add(worker_idx as u64);
+        let worker_iterations = per_worker + u64::from((worker_idx as u64) < remainder);
+        let events = events.clone();
+        let debug_info = debug_info.clone();
+
+        handles.push(thread::spawn(move || -> anyhow::Result<HyperLogLog> {
+            let (mut store, stdout, stderr, test) = instantiate(&engine, module.as_ref())?;
+
+            let mut local_hll = HyperLogLog::new(p);
+            let mut rng = StdRng::seed_from_u64(worker_seed);
+            for _ in 0..worker_iterations {
+                let seed = rng.gen();
+                reset_pipe(&stdout);
+                reset_pipe(&stderr);
+                if let Some(fuel) = max_fuel {
+                    store.set_fuel(fuel)?;
+                }
+                if timeout_ms.is_some() {
+                    // Two ticks, so a call is only interrupted once it has spanned a full tick period.
+                    store.set_epoch_deadline(2);
+                }
+                match test.call(&mut store, seed) {
+                    Ok(result) if result == u64::MAX => {
+                        let out = read_pipe(&stdout)?;
+                        let err = read_pipe(&stderr)?;
+                        if let Some(log) = &events {
+                            log.log(seed, None, "fail")?;
+                        }
+                        let _ = tx.send((seed, format!("stdout:\n{}\nstderr:\n{}", out, err)));
+                    }
+                    Ok(result) => {
+                        if let Some(log) = &events {
+                            log.log(seed, Some(result), "pass")?;
+                        }
+                        local_hll.add(seed, result);
+                    }
+                    Err(trap) => {
+                        let out = read_pipe(&stdout)?;
+                        let err = read_pipe(&stderr)?;
+                        let backtrace = trace::render_trap_backtrace(debug_info.as_ref().as_ref(), &trap);
+                        if let Some(log) = &events {
+                            log.log(seed, None, "fail")?;
+                        }
+                        let _ = tx.send((
+                            seed,
+                            format!("trap: {}\n{}stdout:\n{}\nstderr:\n{}", trap, backtrace, out, err),
+                        ));
+                    }
+                }
+            }
+            Ok(local_hll)
+        }));
+    }
+    drop(failure_tx);
+
+    let mut failures = Vec::new();
+    for (seed, message) in failure_rx {
+        println!("Error: seed {}: {}", seed, message);
+        failures.push(corpus::Failure { seed, message });
+    }
+    if let Some(path) = &report_path {
+        let cases: Vec<report::TestCase> = failures
+            .iter()
+            .map(|f| report::TestCase {
+                name: format!("seed-{}", f.seed),
+                failure: Some(f.message.clone()),
+            })
+            .collect();
+        report::write_junit(
+            path,
+            "pot-cli fuzz",
+            iterations as usize,
+            &cases,
+            start.elapsed().as_secs_f64(),
+        )?;
+    }
+    corpus::append_all(target, failures)?;
+
+    let mut merged = base_hll;
+    println!("Start count: {}", merged.count());
+    for handle in handles {
+        let worker_hll = handle
+            .join()
+            .map_err(|_| anyhow::anyhow!("fuzz worker thread panicked"))??;
+        merged.merge(&worker_hll)?;
+    }
+
+    save_hll(&format!("{}.json", target), &merged)?;
+    println!("End count: {}", merged.count());
+    Ok(())
+}
+
+fn seed_fails(wasm_test: &mut WasmTest, seed: u64) -> anyhow::Result<bool> {
+    Ok(wasm_test.run(seed)?.is_err())
+}
+
+/// Candidates to try shrinking `current` toward: zeroing high bits, halving, and clearing
+/// individual bits, each only kept when it's actually smaller than `current`.
+fn shrink_candidates(current: u64) -> Vec<u64> {
+    let mut candidates = Vec::new();
+    for shift in 0..64 {
+        let candidate = current & ((1u64 << shift) - 1);
+        if candidate < current {
+            candidates.push(candidate);
+        }
+    }
+    if current > 0 {
+        candidates.push(current / 2);
+    }
+    for bit in 0..64 {
+        let candidate = current & !(1u64 << bit);
+        if candidate < current {
+            candidates.push(candidate);
+        }
+    }
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+}
+
+/// Shrinks `seed` toward a simpler reproducer that still satisfies `fails`. Since the
+/// target is driven by a single `u64`, "simpler" means smaller and structurally sparser
+/// (see `shrink_candidates`), and any candidate that still fails replaces the current
+/// seed. This repeats to a fixed point, i.e. until no candidate in a round still fails.
+fn shrink_seed(seed: u64, mut fails: impl FnMut(u64) -> anyhow::Result<bool>) -> anyhow::Result<u64> {
+    let mut current = seed;
+    loop {
+        let mut shrunk = false;
+        for candidate in shrink_candidates(current) {
+            if fails(candidate)? {
+                current = candidate;
+                shrunk = true;
+                break;
+            }
+        }
+        if !shrunk {
+            return Ok(current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod shrink_tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_the_smallest_failing_multiple() {
+        // Anything divisible by 7 "fails"; the smallest such value reachable by repeatedly
+        // zeroing bits/halving from 700 is 7 itself.
+        let minimized = shrink_seed(700, |candidate| Ok(candidate != 0 && candidate % 7 == 0)).unwrap();
+        assert_eq!(minimized, 7);
+    }
+
+    #[test]
+    fn leaves_seed_untouched_when_no_candidate_fails() {
+        let minimized = shrink_seed(42, |_| Ok(false)).unwrap();
+        assert_eq!(minimized, 42);
+    }
+
+    #[test]
+    fn propagates_errors_from_the_failure_check() {
+        let result = shrink_seed(42, |_| Err(anyhow::anyhow!("boom")));
+        assert!(result.is_err());
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
@@ -110,37 +458,228 @@ fn main() -> anyhow::Result<()> {
             target,
             iterations,
             initial_seed,
+            jobs,
+            max_fuel,
+            timeout_ms,
+            report: report_path,
+            events,
         } => {
-            println!("Fuzzing target: {}, iterations: {}", target, iterations);
-            let mut wasm_test = WasmTest::new(&target)?;
-            println!("Start count: {}", wasm_test.hll.count());
+            println!(
+                "Fuzzing target: {}, iterations: {}, jobs: {}",
+                target, iterations, jobs
+            );
+            let events = events.map(|p| report::EventLog::create(&p)).transpose()?;
+            if jobs <= 1 {
+                let start = Instant::now();
+                let mut wasm_test = WasmTest::new(&target, max_fuel, timeout_ms)?;
+                println!("Start count: {}", wasm_test.hll.count());
 
-            let mut rng = initial_seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
-            for _ in 0..iterations {
-                let seed = rng.gen();
-                if let Err(e) = wasm_test.run(seed) {
-                    println!("Error: {:?}", e);
+                let mut rng =
+                    initial_seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+                let mut junit_cases = Vec::new();
+                for _ in 0..iterations {
+                    let seed = rng.gen();
+                    match wasm_test.run(seed) {
+                        Ok(Ok(result)) => {
+                            if let Some(log) = &events {
+                                log.log(seed, Some(result), "pass")?;
+                            }
+                        }
+                        Ok(Err(message)) => {
+                            println!("Error: {}", message);
+                            if let Some(log) = &events {
+                                log.log(seed, None, "fail")?;
+                            }
+                            corpus::append(
+                                &target,
+                                corpus::Failure {
+                                    seed,
+                                    message: message.clone(),
+                                },
+                            )?;
+                            junit_cases.push(report::TestCase {
+                                name: format!("seed-{}", seed),
+                                failure: Some(message),
+                            });
+                        }
+                        Err(e) => println!("Error: {:?}", e),
+                    }
+                }
+                wasm_test.save()?;
+                println!("End count: {}", wasm_test.hll.count());
+                if let Some(path) = &report_path {
+                    report::write_junit(
+                        path,
+                        "pot-cli fuzz",
+                        iterations as usize,
+                        &junit_cases,
+                        start.elapsed().as_secs_f64(),
+                    )?;
                 }
+            } else {
+                run_parallel(
+                    &target,
+                    iterations,
+                    initial_seed,
+                    jobs,
+                    max_fuel,
+                    timeout_ms,
+                    report_path,
+                    events,
+                )?;
             }
-            wasm_test.save()?;
-            println!("End count: {}", wasm_test.hll.count());
             Ok(())
         }
-        Commands::Verify { target } => {
-            let mut wasm_test = WasmTest::new(&target)?;
+        Commands::Verify {
+            target,
+            max_fuel,
+            timeout_ms,
+            report: report_path,
+            events,
+        } => {
+            let events = events.map(|p| report::EventLog::create(&p)).transpose()?;
+            let start = Instant::now();
+            let mut wasm_test = WasmTest::new(&target, max_fuel, timeout_ms)?;
             let hll = wasm_test.hll.clone();
+            let mut junit_cases = Vec::new();
+            let mut any_failed = false;
             for (&seed, &hash) in hll.seeds.iter().zip(hll.hashes.iter()) {
                 let result = wasm_test.run(seed)?;
+                if let Some(log) = &events {
+                    log.log(
+                        seed,
+                        result.as_ref().ok().copied(),
+                        if result == Ok(hash) { "pass" } else { "fail" },
+                    )?;
+                }
                 if result != Ok(hash) {
+                    any_failed = true;
+                    junit_cases.push(report::TestCase {
+                        name: format!("seed-{}", seed),
+                        failure: Some(match &result {
+                            Ok(actual) => format!("expected hash {}, got {}", hash, actual),
+                            Err(message) => message.clone(),
+                        }),
+                    });
                     println!(
                         "Error: Seed: {}, hash: {}, result: {:?} ❌",
                         seed, hash, result
                     );
-                    return Err(anyhow::anyhow!("Verification failed"));
+                } else {
+                    junit_cases.push(report::TestCase {
+                        name: format!("seed-{}", seed),
+                        failure: None,
+                    });
                 }
             }
+            if let Some(path) = &report_path {
+                report::write_junit(
+                    path,
+                    "pot-cli verify",
+                    hll.seeds.len(),
+                    &junit_cases,
+                    start.elapsed().as_secs_f64(),
+                )?;
+            }
+            if any_failed {
+                return Err(anyhow::anyhow!("Verification failed"));
+            }
             println!("Verification passed ✅");
             Ok(())
         }
+        Commands::Merge { inputs, output } => {
+            let mut inputs = inputs.into_iter();
+            let first = inputs.next().expect("clap enforces at least one input");
+            let mut merged = load_hll(&first)?;
+            for path in inputs {
+                merged.merge(&load_hll(&path)?)?;
+            }
+            save_hll(&output, &merged)?;
+            println!(
+                "Merged sketch written to {} (count: {})",
+                output,
+                merged.count()
+            );
+            Ok(())
+        }
+        Commands::Minimize {
+            target,
+            seed,
+            max_fuel,
+            timeout_ms,
+        } => {
+            let failures = corpus::load(&target)?;
+            let failure = match seed {
+                Some(seed) => failures
+                    .into_iter()
+                    .find(|f| f.seed == seed)
+                    .ok_or_else(|| anyhow::anyhow!("no recorded failure for seed {}", seed))?,
+                None => failures
+                    .into_iter()
+                    .last()
+                    .ok_or_else(|| anyhow::anyhow!("no recorded failures for {}", target))?,
+            };
+
+            let mut wasm_test = WasmTest::new(&target, max_fuel, timeout_ms)?;
+            let minimized = shrink_seed(failure.seed, |candidate| {
+                seed_fails(&mut wasm_test, candidate)
+            })?;
+            let message = match wasm_test.run(minimized)? {
+                Ok(_) => anyhow::bail!("seed {} no longer fails", minimized),
+                Err(message) => message,
+            };
+            println!("Minimized seed: {} -> {}", failure.seed, minimized);
+            println!("{}", message);
+            Ok(())
+        }
+        Commands::Check {
+            target,
+            vectors,
+            export,
+            max_fuel,
+            timeout_ms,
+        } => {
+            if let Some(export_path) = export {
+                let hll = load_hll(&format!("{}.json", target))?;
+                let exported = conformance::from_hll(&hll);
+                conformance::save(&export_path, &exported)?;
+                println!(
+                    "Exported {} conformance vectors to {}",
+                    exported.len(),
+                    export_path
+                );
+                return Ok(());
+            }
+
+            let vectors_path = vectors.ok_or_else(|| {
+                anyhow::anyhow!("--vectors <file> is required unless --export is given")
+            })?;
+            let cases = conformance::load(&vectors_path)?;
+            let mut wasm_test = WasmTest::new(&target, max_fuel, timeout_ms)?;
+            let mut failed = 0;
+            for case in &cases {
+                match wasm_test.run(case.seed)? {
+                    Ok(actual) if actual == case.expected_hash => {
+                        println!("{}: pass", case.name);
+                    }
+                    Ok(actual) => {
+                        failed += 1;
+                        println!(
+                            "{}: FAIL (expected hash {}, got {})",
+                            case.name, case.expected_hash, actual
+                        );
+                    }
+                    Err(message) => {
+                        failed += 1;
+                        println!("{}: FAIL ({})", case.name, message);
+                    }
+                }
+            }
+            println!("{}/{} passed", cases.len() - failed, cases.len());
+            if failed > 0 {
+                return Err(anyhow::anyhow!("{} conformance check(s) failed", failed));
+            }
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,151 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+#[derive(Serialize)]
+struct Event<'a> {
+    seed: u64,
+    result: Option<u64>,
+    status: &'a str,
+    timestamp: u64,
+}
+
+/// Appends one JSON object per line to an NDJSON file, flushing after every write so an
+/// external watcher can `tail -f` it while the run is still in progress. Cloning shares the
+/// same underlying file, so every fuzzing worker thread can log through its own handle.
+#[derive(Clone)]
+pub struct EventLog(Arc<Mutex<File>>);
+
+impl EventLog {
+    pub fn create(path: &str) -> anyhow::Result<Self> {
+        Ok(Self(Arc::new(Mutex::new(File::create(path)?))))
+    }
+
+    pub fn log(&self, seed: u64, result: Option<u64>, status: &str) -> anyhow::Result<()> {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let mut file = self.0.lock().unwrap();
+        serde_json::to_writer(
+            &mut *file,
+            &Event {
+                seed,
+                result,
+                status,
+                timestamp,
+            },
+        )?;
+        writeln!(file)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// One JUnit `<testcase>`; `failure` carries the captured stdout/stderr (or mismatch
+/// details) for a failing case, and is `None` for a passing one.
+pub struct TestCase {
+    pub name: String,
+    pub failure: Option<String>,
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escapes a literal `]]>` inside CDATA content, which would otherwise close the section
+/// early and produce malformed XML.
+fn escape_cdata(s: &str) -> String {
+    s.replace("]]>", "]]]]><![CDATA[>")
+}
+
+/// Writes a JUnit-style XML report: aggregate `tests`/`failures`/`time` on the
+/// `<testsuite>`, and one `<testcase>` per entry in `cases`.
+pub fn write_junit(
+    path: &str,
+    suite_name: &str,
+    tests: usize,
+    cases: &[TestCase],
+    elapsed_secs: f64,
+) -> anyhow::Result<()> {
+    let failures = cases.iter().filter(|c| c.failure.is_some()).count();
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        escape_xml(suite_name),
+        tests,
+        failures,
+        elapsed_secs
+    );
+    for case in cases {
+        xml.push_str(&format!(
+            "  <testcase name=\"{}\">\n",
+            escape_xml(&case.name)
+        ));
+        if let Some(message) = &case.failure {
+            xml.push_str(&format!(
+                "    <failure message=\"seed failed\"><![CDATA[{}]]></failure>\n",
+                escape_cdata(message)
+            ));
+        }
+        xml.push_str("  </testcase>\n");
+    }
+    xml.push_str("</testsuite>\n");
+    std::fs::write(path, xml)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("pot-cli-{}-{}.xml", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn write_junit_reports_aggregate_counts() {
+        let path = temp_path("aggregate");
+        let cases = vec![
+            TestCase {
+                name: "seed-1".to_string(),
+                failure: None,
+            },
+            TestCase {
+                name: "seed-2".to_string(),
+                failure: Some("boom".to_string()),
+            },
+        ];
+
+        write_junit(&path, "fuzz", 2, &cases, 1.5).unwrap();
+        let xml = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(xml.contains("tests=\"2\" failures=\"1\" time=\"1.500\""));
+        assert!(xml.contains("<testcase name=\"seed-1\">"));
+        assert!(xml.contains("<testcase name=\"seed-2\">"));
+        assert!(xml.contains("<![CDATA[boom]]>"));
+    }
+
+    #[test]
+    fn write_junit_escapes_literal_cdata_terminators_in_failures() {
+        let path = temp_path("cdata-escape");
+        let cases = vec![TestCase {
+            name: "seed-3".to_string(),
+            failure: Some("output contained ]]> here".to_string()),
+        }];
+
+        write_junit(&path, "fuzz", 1, &cases, 0.0).unwrap();
+        let xml = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!xml.contains("]]> here]]></failure>"));
+        assert!(xml.contains("]]]]><![CDATA[>"));
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use addr2line::gimli;
+use gimli::{EndianArcSlice, RunTimeEndian};
+
+/// Symbolicates wasm trap offsets into `function (file:line)` frames using the module's
+/// embedded DWARF debug sections, when present.
+///
+/// Uses `Arc`-backed (not `Rc`-backed) sections so `DebugInfo` is `Send + Sync` and can be
+/// shared into the `--jobs` worker threads.
+pub struct DebugInfo {
+    context: addr2line::Context<EndianArcSlice<RunTimeEndian>>,
+    /// Start of the code section's contents; wasm DWARF addresses are relative to it.
+    code_section_offset: u64,
+}
+
+impl DebugInfo {
+    /// Parses the DWARF sections out of a wasm module's bytes. Returns `None` when the
+    /// module wasn't compiled with debug info, so callers can skip symbolication entirely.
+    pub fn load(wasm_bytes: &[u8]) -> anyhow::Result<Option<Self>> {
+        let mut sections: HashMap<&str, &[u8]> = HashMap::new();
+        let mut code_section_offset = 0;
+        for payload in wasmparser::Parser::new(0).parse_all(wasm_bytes) {
+            match payload? {
+                wasmparser::Payload::CustomSection(reader) if reader.name().starts_with(".debug_") => {
+                    sections.insert(reader.name(), reader.data());
+                }
+                wasmparser::Payload::CodeSectionStart { range, .. } => {
+                    code_section_offset = range.start as u64;
+                }
+                _ => {}
+            }
+        }
+        if sections.is_empty() {
+            return Ok(None);
+        }
+
+        let endian = RunTimeEndian::Little;
+        let load_section = |id: gimli::SectionId| -> Result<EndianArcSlice<RunTimeEndian>, gimli::Error> {
+            let data = sections.get(id.name()).copied().unwrap_or(&[]);
+            Ok(EndianArcSlice::new(Arc::from(data), endian))
+        };
+        let dwarf = gimli::Dwarf::load(load_section)?;
+        let context = addr2line::Context::from_dwarf(dwarf)?;
+        Ok(Some(Self {
+            context,
+            code_section_offset,
+        }))
+    }
+
+    /// Resolves an offset within the module to its call stack, innermost frame first.
+    pub fn symbolicate(&self, module_offset: u64) -> Vec<String> {
+        let Some(code_offset) = module_offset.checked_sub(self.code_section_offset) else {
+            return Vec::new();
+        };
+        let Ok(mut frames) = self.context.find_frames(code_offset).skip_all_loads() else {
+            return Vec::new();
+        };
+        let mut resolved = Vec::new();
+        while let Ok(Some(frame)) = frames.next() {
+            let function = frame
+                .function
+                .as_ref()
+                .and_then(|f| f.demangle().ok().map(|n| n.to_string()))
+                .unwrap_or_else(|| "<unknown>".to_string());
+            let location = frame
+                .location
+                .map(|loc| {
+                    format!(
+                        "{}:{}",
+                        loc.file.unwrap_or("<unknown>"),
+                        loc.line.unwrap_or(0)
+                    )
+                })
+                .unwrap_or_else(|| "<unknown>".to_string());
+            resolved.push(format!("{} ({})", function, location));
+        }
+        resolved
+    }
+}
+
+/// Renders a trap's wasm backtrace through `debug_info`, or an empty string when either the
+/// trap carries no backtrace or the module has no debug info to resolve it against.
+pub fn render_trap_backtrace(debug_info: Option<&DebugInfo>, trap: &anyhow::Error) -> String {
+    let Some(debug_info) = debug_info else {
+        return String::new();
+    };
+    let Some(backtrace) = trap.downcast_ref::<wasmtime::WasmBacktrace>() else {
+        return String::new();
+    };
+
+    let mut out = String::from("backtrace:\n");
+    for frame in backtrace.frames() {
+        let Some(offset) = frame.module_offset() else {
+            continue;
+        };
+        for line in debug_info.symbolicate(offset as u64) {
+            out.push_str("  at ");
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}